@@ -3,12 +3,50 @@ use std::marker::PhantomData;
 use rayon::prelude::*;
 use rand::prelude::*;
 
-pub trait Individual: Clone + Debug + Send + Sync {
+#[cfg(feature = "global_cache")]
+use std::collections::HashMap;
+#[cfg(feature = "global_cache")]
+use std::sync::Mutex;
+#[cfg(feature = "global_cache")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// With the `global_cache` feature enabled, individuals must additionally be
+// hashable so they can key the memoization map; without it the bound vanishes
+// and no extra requirement is placed on user types.
+#[cfg(feature = "global_cache")]
+pub trait CacheBound: std::hash::Hash + Eq {}
+#[cfg(feature = "global_cache")]
+impl<T: std::hash::Hash + Eq> CacheBound for T {}
+#[cfg(not(feature = "global_cache"))]
+pub trait CacheBound {}
+#[cfg(not(feature = "global_cache"))]
+impl<T> CacheBound for T {}
+
+pub trait Individual: Clone + Debug + Send + Sync + CacheBound {
     fn fitness(&self) -> f64;
     fn crossover(&self, other: &Self) -> (Self, Self);
     fn mutate(&mut self);
 }
 
+// Optimisation direction. The library defaults to `Maximize`; choose
+// `Minimize` to optimise a cost (error, distance, loss) without negating your
+// fitness function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Objective {
+    Maximize,
+    Minimize,
+}
+
+// Single source of truth for fitness comparison: is `a` a better fitness than
+// `b` under the given objective? All selection and elite decisions route
+// through this.
+pub fn is_better(a: f64, b: f64, objective: Objective) -> bool {
+    match objective {
+        Objective::Maximize => a > b,
+        Objective::Minimize => a < b,
+    }
+}
+
 pub struct GeneticAlgorithm<I, S, C, M>
 where
     I: Individual,
@@ -20,6 +58,14 @@ where
     selection_strategy: S,
     crossover_operator: C,
     mutation_operator: M,
+    elite_count: usize,
+    objective: Objective,
+    #[cfg(feature = "global_cache")]
+    cache: Mutex<HashMap<I, f64>>,
+    #[cfg(feature = "global_cache")]
+    cache_hits: AtomicUsize,
+    #[cfg(feature = "global_cache")]
+    cache_misses: AtomicUsize,
     _marker: PhantomData<I>,
 }
 
@@ -41,33 +87,197 @@ where
             selection_strategy,
             crossover_operator,
             mutation_operator,
+            elite_count: 0,
+            objective: Objective::Maximize,
+            #[cfg(feature = "global_cache")]
+            cache: Mutex::new(HashMap::new()),
+            #[cfg(feature = "global_cache")]
+            cache_hits: AtomicUsize::new(0),
+            #[cfg(feature = "global_cache")]
+            cache_misses: AtomicUsize::new(0),
             _marker: PhantomData,
         }
     }
 
-    pub fn evolve<R: Rng + Send + Sync>(
+    // Upper bound on the number of memoized fitness values; the cache is
+    // cleared wholesale once it is reached.
+    #[cfg(feature = "global_cache")]
+    const CACHE_CAPACITY: usize = 100_000;
+
+    // Evaluate an individual's fitness, consulting the global cache first when
+    // the `global_cache` feature is enabled. For expensive fitness functions
+    // this avoids re-evaluating genomes that recur through elitism or
+    // duplicate offspring.
+    #[cfg(not(feature = "global_cache"))]
+    fn evaluate(&self, individual: &I) -> f64 {
+        individual.fitness()
+    }
+
+    #[cfg(feature = "global_cache")]
+    fn evaluate(&self, individual: &I) -> f64 {
+        if let Some(&fitness) = self.cache.lock().unwrap().get(individual) {
+            self.cache_hits.fetch_add(1, Ordering::Relaxed);
+            return fitness;
+        }
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        let fitness = individual.fitness();
+        let mut cache = self.cache.lock().unwrap();
+        // Bound memory growth: genomes drift every generation, so an unbounded
+        // map would accumulate stale entries forever. Drop the whole map once
+        // it outgrows the cap rather than tracking per-entry ages.
+        if cache.len() >= Self::CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(individual.clone(), fitness);
+        fitness
+    }
+
+    // Number of fitness lookups served from the cache.
+    #[cfg(feature = "global_cache")]
+    pub fn cache_hits(&self) -> usize {
+        self.cache_hits.load(Ordering::Relaxed)
+    }
+
+    // Number of fitness lookups that required a fresh evaluation.
+    #[cfg(feature = "global_cache")]
+    pub fn cache_misses(&self) -> usize {
+        self.cache_misses.load(Ordering::Relaxed)
+    }
+
+    // Preserve the top `elite_count` individuals by fitness unchanged into each
+    // new generation, guarding against the best solution being lost to
+    // crossover or mutation.
+    pub fn with_elitism(mut self, elite_count: usize) -> Self {
+        self.elite_count = elite_count;
+        self
+    }
+
+    // Set the optimisation direction. Defaults to `Objective::Maximize`.
+    pub fn with_objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    pub fn evolve<R: Rng>(
         &self,
-        generations: usize,
+        stop_criterion: &dyn StopCriterion<I>,
         rng: &mut R,
         initial_population: Option<Vec<I>>,
+        mut observer: Option<&mut dyn GenerationObserver<I>>,
     ) -> Vec<I> {
         let mut population = match initial_population {
             Some(pop) => pop,
-            None => (0..self.population_size)
-                .into_par_iter()
-                .map(|_| self.generate_individual(rng))
-                .collect(),
+            None => {
+                // Draw one seed per individual from the master rng so the
+                // parallel workers each get an independent but reproducible
+                // stream (a shared `&mut rng` cannot cross rayon threads).
+                let seeds: Vec<u64> = (0..self.population_size).map(|_| rng.gen()).collect();
+                seeds
+                    .into_par_iter()
+                    .map(|seed| self.generate_individual(&mut StdRng::seed_from_u64(seed)))
+                    .collect()
+            }
         };
 
-        for _ in 0..generations {
+        let mut fitness_history: Vec<f64> = Vec::new();
+
+        for generation in 0.. {
             let fitness_values: Vec<f64> = population
                 .par_iter()
-                .map(|individual| individual.fitness())
+                .map(|individual| self.evaluate(individual))
                 .collect();
 
+            let best_fitness = fitness_values
+                .iter()
+                .cloned()
+                .reduce(|a, b| if is_better(a, b, self.objective) { a } else { b })
+                .unwrap_or(f64::NAN);
+            fitness_history.push(best_fitness);
+
+            if let Some(observer) = observer.as_deref_mut() {
+                let n = fitness_values.len();
+                let mean_fitness = if n > 0 {
+                    fitness_values.iter().sum::<f64>() / n as f64
+                } else {
+                    f64::NAN
+                };
+                let std_fitness = if n > 0 {
+                    let variance = fitness_values
+                        .iter()
+                        .map(|&f| (f - mean_fitness).powi(2))
+                        .sum::<f64>()
+                        / n as f64;
+                    variance.sqrt()
+                } else {
+                    f64::NAN
+                };
+                let best_index = (0..n)
+                    .reduce(|a, b| {
+                        if is_better(fitness_values[a], fitness_values[b], self.objective) {
+                            a
+                        } else {
+                            b
+                        }
+                    })
+                    .unwrap_or(0);
+                let mut distinct: Vec<f64> = fitness_values.clone();
+                distinct.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+                distinct.dedup();
+                let stats = GenerationStats {
+                    generation,
+                    best_fitness,
+                    mean_fitness,
+                    std_fitness,
+                    best_individual: &population[best_index],
+                    num_distinct: distinct.len(),
+                };
+                observer.on_generation(&stats);
+            }
+
+            if stop_criterion.should_stop(generation, best_fitness, &fitness_history, self.objective)
+            {
+                break;
+            }
+
+            // Normalise the slope so that positive progress always means
+            // "improving" regardless of objective direction, before handing it
+            // to adaptive rates.
+            let raw_slope = least_squares_slope(&fitness_history);
+            let progress = match self.objective {
+                Objective::Maximize => raw_slope,
+                Objective::Minimize => -raw_slope,
+            };
+            let ctx = EvolveContext {
+                generation,
+                progress,
+                n_solutions: population.len(),
+                objective: self.objective,
+            };
+
+            // Carry the fittest individuals forward untouched. They bypass the
+            // selection/crossover/mutation path entirely so their fitness is
+            // truly preserved.
+            let elites: Vec<I> = if self.elite_count > 0 {
+                let mut indices: Vec<usize> = (0..population.len()).collect();
+                indices.sort_unstable_by(|&a, &b| {
+                    if is_better(fitness_values[a], fitness_values[b], self.objective) {
+                        std::cmp::Ordering::Less
+                    } else {
+                        std::cmp::Ordering::Greater
+                    }
+                });
+                indices
+                    .into_iter()
+                    .take(self.elite_count.min(population.len()))
+                    .map(|index| population[index].clone())
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
             let parents = self
                 .selection_strategy
-                .select(&population, &fitness_values, rng);
+                .select(&population, &fitness_values, &ctx, rng);
 
             let offspring: Vec<I> = parents
                 .par_chunks(2)
@@ -80,13 +290,27 @@ where
                 })
                 .collect();
 
-            population = offspring
+            // Trim deterministically before mutating so the surviving offspring
+            // are reproducible for a given seed (rayon's `take_any` would keep
+            // whichever items finished first, in unspecified order).
+            let slots = self.population_size.saturating_sub(elites.len());
+            let mut offspring = offspring;
+            offspring.truncate(slots);
+            // Seed a per-offspring rng from the master stream so mutation is
+            // both parallel and reproducible for a given seed.
+            let seeds: Vec<u64> = (0..offspring.len()).map(|_| rng.gen()).collect();
+            let mut next_population: Vec<I> = offspring
                 .into_par_iter()
-                .map(|mut individual| {
-                    self.mutation_operator.mutate(&mut individual, rng);
+                .zip(seeds)
+                .map(|(mut individual, seed)| {
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    self.mutation_operator.mutate(&mut individual, &ctx, &mut rng);
                     individual
                 })
                 .collect();
+
+            next_population.extend(elites);
+            population = next_population;
         }
 
         population
@@ -98,8 +322,97 @@ where
     }
 }
 
+// Least-squares slope of the most recent best-fitness values, used as the
+// `progress` signal for adaptive rates. Returns 0.0 until at least two values
+// are available.
+fn least_squares_slope(history: &[f64]) -> f64 {
+    const WINDOW: usize = 10;
+    let recent = &history[history.len().saturating_sub(WINDOW)..];
+    let n = recent.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let n_f = n as f64;
+    let sum_x: f64 = (0..n).map(|x| x as f64).sum();
+    let sum_y: f64 = recent.iter().sum();
+    let sum_xy: f64 = recent.iter().enumerate().map(|(x, &y)| x as f64 * y).sum();
+    let sum_xx: f64 = (0..n).map(|x| (x as f64).powi(2)).sum();
+    let denominator = n_f * sum_xx - sum_x * sum_x;
+    if denominator == 0.0 {
+        return 0.0;
+    }
+    (n_f * sum_xy - sum_x * sum_y) / denominator
+}
+
+// Context describing how evolution is progressing, handed to selection and
+// mutation operators each generation so adaptive rates can react to it.
+// `progress` is the least-squares slope of the best fitness over the most
+// recent generations, sign-adjusted so that a positive value always means
+// improvement under the objective (the raw slope is negated for `Minimize`).
+#[derive(Clone, Copy, Debug)]
+pub struct EvolveContext {
+    pub generation: usize,
+    pub progress: f64,
+    pub n_solutions: usize,
+    pub objective: Objective,
+}
+
+// A rate (mutation probability, selective pressure, ...) that may vary with
+// how evolution is progressing rather than being fixed for the whole run.
+pub trait Rate: Send + Sync {
+    fn get(&self, generation: usize, progress: f64, n_solutions: usize) -> f64;
+}
+
+// A fixed rate that ignores progress.
+pub struct ConstantRate(pub f64);
+
+impl Rate for ConstantRate {
+    fn get(&self, _generation: usize, _progress: f64, _n_solutions: usize) -> f64 {
+        self.0
+    }
+}
+
+// Interpolate linearly from `start` to `end` over `over_generations`, then
+// hold at `end`.
+pub struct LinearRate {
+    pub start: f64,
+    pub end: f64,
+    pub over_generations: usize,
+}
+
+impl Rate for LinearRate {
+    fn get(&self, generation: usize, _progress: f64, _n_solutions: usize) -> f64 {
+        if self.over_generations == 0 {
+            return self.end;
+        }
+        let t = (generation as f64 / self.over_generations as f64).min(1.0);
+        self.start + (self.end - self.start) * t
+    }
+}
+
+// Sigmoid of the recent fitness slope: `a / (1 + exp(c * slope)) + b`, clamped
+// to `[0, 1]`. As improvement stalls (slope → 0) the rate rises to escape
+// local optima; as it accelerates the rate falls toward `b`.
+pub struct SlopeRate {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl Rate for SlopeRate {
+    fn get(&self, _generation: usize, progress: f64, _n_solutions: usize) -> f64 {
+        (self.a / (1.0 + (self.c * progress).exp()) + self.b).clamp(0.0, 1.0)
+    }
+}
+
 pub trait SelectionStrategy<I: Individual>: Send + Sync {
-    fn select<R: Rng>(&self, population: &[I], fitness_values: &[f64], rng: &mut R) -> Vec<I>;
+    fn select<R: Rng>(
+        &self,
+        population: &[I],
+        fitness_values: &[f64],
+        ctx: &EvolveContext,
+        rng: &mut R,
+    ) -> Vec<I>;
 }
 
 pub trait CrossoverOperator<I: Individual>: Send + Sync {
@@ -107,7 +420,152 @@ pub trait CrossoverOperator<I: Individual>: Send + Sync {
 }
 
 pub trait MutationOperator<I: Individual>: Send + Sync {
-    fn mutate<R: Rng>(&self, individual: &mut I, rng: &mut R);
+    fn mutate<R: Rng>(&self, individual: &mut I, ctx: &EvolveContext, rng: &mut R);
+}
+
+// Structured snapshot of a single generation, handed to an observer so callers
+// can log progress, plot convergence, or drive external early-stopping without
+// forking `evolve`.
+pub struct GenerationStats<'a, I: Individual> {
+    pub generation: usize,
+    pub best_fitness: f64,
+    pub mean_fitness: f64,
+    pub std_fitness: f64,
+    pub best_individual: &'a I,
+    // Number of distinct *fitness values* in the population, used as a cheap
+    // diversity proxy. Individuals with different genomes but equal fitness
+    // collapse into one, so this can undercount genuine genetic diversity.
+    pub num_distinct: usize,
+}
+
+// Observer invoked once per generation with the generation's statistics.
+// Implemented for any `FnMut(&GenerationStats<I>)` so a closure can be passed
+// directly.
+pub trait GenerationObserver<I: Individual> {
+    fn on_generation(&mut self, stats: &GenerationStats<I>);
+}
+
+impl<I: Individual, F: FnMut(&GenerationStats<I>)> GenerationObserver<I> for F {
+    fn on_generation(&mut self, stats: &GenerationStats<I>) {
+        self(stats)
+    }
+}
+
+// Stop criteria: decide when `evolve` should terminate. Evaluated each
+// generation once fitness has been computed, so implementations can react to
+// both the current best fitness and the full history of best-per-generation
+// values. `objective` tells criteria which direction counts as improvement so
+// they work equally for minimize and maximize problems.
+pub trait StopCriterion<I: Individual>: Send + Sync {
+    fn should_stop(
+        &self,
+        generation: usize,
+        best_fitness: f64,
+        fitness_history: &[f64],
+        objective: Objective,
+    ) -> bool;
+}
+
+// Stop once the best fitness reaches the target value (crosses it in the
+// direction that counts as improvement under the objective).
+pub struct FitnessThreshold(pub f64);
+
+impl<I: Individual> StopCriterion<I> for FitnessThreshold {
+    fn should_stop(
+        &self,
+        _generation: usize,
+        best_fitness: f64,
+        _fitness_history: &[f64],
+        objective: Objective,
+    ) -> bool {
+        best_fitness == self.0 || is_better(best_fitness, self.0, objective)
+    }
+}
+
+// Stop after a fixed number of generations.
+pub struct MaxGenerations(pub usize);
+
+impl<I: Individual> StopCriterion<I> for MaxGenerations {
+    fn should_stop(
+        &self,
+        generation: usize,
+        _best_fitness: f64,
+        _fitness_history: &[f64],
+        _objective: Objective,
+    ) -> bool {
+        generation >= self.0
+    }
+}
+
+// Stop when the best fitness improves by less than `epsilon` across the last
+// `window` generations, i.e. evolution has stagnated. The improvement is
+// measured in the objective's direction so a decreasing best fitness still
+// counts as progress under `Objective::Minimize`.
+pub struct SlopeStagnation {
+    pub window: usize,
+    pub epsilon: f64,
+}
+
+impl<I: Individual> StopCriterion<I> for SlopeStagnation {
+    fn should_stop(
+        &self,
+        _generation: usize,
+        _best_fitness: f64,
+        fitness_history: &[f64],
+        objective: Objective,
+    ) -> bool {
+        if self.window == 0 || fitness_history.len() <= self.window {
+            return false;
+        }
+        // Compare the latest best fitness with the one `window` generations
+        // earlier.
+        let latest = fitness_history[fitness_history.len() - 1];
+        let earlier = fitness_history[fitness_history.len() - 1 - self.window];
+        let raw = latest - earlier;
+        let improvement = match objective {
+            Objective::Maximize => raw,
+            Objective::Minimize => -raw,
+        };
+        improvement < self.epsilon
+    }
+}
+
+// Combinator: stop only when both criteria agree.
+pub struct And<A, B>(pub A, pub B);
+
+impl<I: Individual, A: StopCriterion<I>, B: StopCriterion<I>> StopCriterion<I> for And<A, B> {
+    fn should_stop(
+        &self,
+        generation: usize,
+        best_fitness: f64,
+        fitness_history: &[f64],
+        objective: Objective,
+    ) -> bool {
+        self.0
+            .should_stop(generation, best_fitness, fitness_history, objective)
+            && self
+                .1
+                .should_stop(generation, best_fitness, fitness_history, objective)
+    }
+}
+
+// Combinator: stop as soon as either criterion fires.
+pub struct Or<A, B>(pub A, pub B);
+
+impl<I: Individual, A: StopCriterion<I>, B: StopCriterion<I>> StopCriterion<I> for Or<A, B> {
+    fn should_stop(
+        &self,
+        generation: usize,
+        best_fitness: f64,
+        fitness_history: &[f64],
+        objective: Objective,
+    ) -> bool {
+        self.0
+            .should_stop(generation, best_fitness, fitness_history, objective)
+            || self
+                .1
+                .should_stop(generation, best_fitness, fitness_history, objective)
+    }
 }
 
 // Example implementation of selection strategy: Tournament Selection
@@ -116,7 +574,13 @@ pub struct TournamentSelection {
 }
 
 impl<I: Individual> SelectionStrategy<I> for TournamentSelection {
-    fn select<R: Rng>(&self, population: &[I], fitness_values: &[f64], rng: &mut R) -> Vec<I> {
+    fn select<R: Rng>(
+        &self,
+        population: &[I],
+        fitness_values: &[f64],
+        ctx: &EvolveContext,
+        rng: &mut R,
+    ) -> Vec<I> {
         (0..population.len())
             .map(|_| {
                 let tournament = (0..self.tournament_size)
@@ -124,7 +588,13 @@ impl<I: Individual> SelectionStrategy<I> for TournamentSelection {
                     .collect::<Vec<_>>();
                 tournament
                     .into_iter()
-                    .max_by(|&a, &b| fitness_values[a].partial_cmp(&fitness_values[b]).unwrap())
+                    .reduce(|a, b| {
+                        if is_better(fitness_values[a], fitness_values[b], ctx.objective) {
+                            a
+                        } else {
+                            b
+                        }
+                    })
                     .map(|index| population[index].clone())
                     .unwrap()
             })
@@ -132,12 +602,171 @@ impl<I: Individual> SelectionStrategy<I> for TournamentSelection {
     }
 }
 
+// Fitness-proportionate (roulette-wheel) selection. Fitness values are turned
+// into selection probabilities and each slot is drawn by sampling the
+// cumulative distribution, so fitter individuals are picked more often while
+// weaker ones retain a chance.
+pub struct RouletteWheelSelection;
+
+impl<I: Individual> SelectionStrategy<I> for RouletteWheelSelection {
+    fn select<R: Rng>(
+        &self,
+        population: &[I],
+        fitness_values: &[f64],
+        ctx: &EvolveContext,
+        rng: &mut R,
+    ) -> Vec<I> {
+        let n = population.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        // Map fitness to a "larger is better" weight honouring the objective,
+        // then shift so the worst individual maps to a small positive floor.
+        // This keeps every weight positive (guarding against negative fitness)
+        // so the probabilities are well-defined.
+        const FLOOR: f64 = 1e-6;
+        let weights: Vec<f64> = fitness_values
+            .par_iter()
+            .map(|&f| match ctx.objective {
+                Objective::Maximize => f,
+                Objective::Minimize => -f,
+            })
+            .collect();
+        let min_weight = weights
+            .iter()
+            .cloned()
+            .fold(f64::INFINITY, f64::min);
+        let shifted: Vec<f64> = weights
+            .par_iter()
+            .map(|&w| w - min_weight + FLOOR)
+            .collect();
+        let total: f64 = shifted.iter().sum();
+
+        let cumulative = cumulative_probabilities(&shifted, total);
+
+        (0..n)
+            .map(|_| {
+                let u: f64 = rng.gen();
+                let index = cumulative.partition_point(|&c| c <= u).min(n - 1);
+                population[index].clone()
+            })
+            .collect()
+    }
+}
+
+// Linear-ranking selection. Individuals are ranked by fitness and assigned a
+// selection probability from their rank rather than their raw fitness,
+// damping premature convergence when a few individuals dominate. The
+// selective-pressure parameter `s` lies in `[1, 2]`: 1.0 is uniform, 2.0 gives
+// the steepest bias toward the best.
+pub struct RankSelection {
+    pub selective_pressure: f64,
+}
+
+impl<I: Individual> SelectionStrategy<I> for RankSelection {
+    fn select<R: Rng>(
+        &self,
+        population: &[I],
+        fitness_values: &[f64],
+        ctx: &EvolveContext,
+        rng: &mut R,
+    ) -> Vec<I> {
+        let n = population.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        // Order individuals worst-to-best so rank 0 is the weakest and rank
+        // n-1 the strongest, under the current objective.
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_unstable_by(|&a, &b| {
+            if is_better(fitness_values[a], fitness_values[b], ctx.objective) {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Less
+            }
+        });
+
+        let s = self.selective_pressure.clamp(1.0, 2.0);
+        let n_f = n as f64;
+        // Probability assigned to each individual keyed by its original index.
+        let mut probabilities = vec![0.0_f64; n];
+        for (rank, &index) in order.iter().enumerate() {
+            probabilities[index] = if n == 1 {
+                1.0
+            } else {
+                (2.0 - s) / n_f + 2.0 * rank as f64 * (s - 1.0) / (n_f * (n_f - 1.0))
+            };
+        }
+        let total: f64 = probabilities.iter().sum();
+
+        let cumulative = cumulative_probabilities(&probabilities, total);
+
+        (0..n)
+            .map(|_| {
+                let u: f64 = rng.gen();
+                let index = cumulative.partition_point(|&c| c <= u).min(n - 1);
+                population[index].clone()
+            })
+            .collect()
+    }
+}
+
+// Build a cumulative-probability array from per-individual weights. Falls back
+// to a uniform distribution when the weights sum to zero or are non-finite.
+fn cumulative_probabilities(weights: &[f64], total: f64) -> Vec<f64> {
+    let n = weights.len();
+    let mut cumulative = Vec::with_capacity(n);
+    let mut acc = 0.0;
+    if total > 0.0 && total.is_finite() {
+        for &w in weights {
+            acc += w / total;
+            cumulative.push(acc);
+        }
+    } else {
+        let step = 1.0 / n as f64;
+        for _ in 0..n {
+            acc += step;
+            cumulative.push(acc);
+        }
+    }
+    cumulative
+}
+
 // Example usage
 #[derive(Clone, Debug)]
 struct MyIndividual {
     genes: Vec<f64>,
 }
 
+// `f64` is neither `Hash` nor `Eq`, so under the `global_cache` feature the
+// example hashes and compares on the genes' raw bit patterns to satisfy the
+// `CacheBound` requirement.
+#[cfg(feature = "global_cache")]
+impl std::hash::Hash for MyIndividual {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for gene in &self.genes {
+            gene.to_bits().hash(state);
+        }
+    }
+}
+
+#[cfg(feature = "global_cache")]
+impl PartialEq for MyIndividual {
+    fn eq(&self, other: &Self) -> bool {
+        self.genes.len() == other.genes.len()
+            && self
+                .genes
+                .iter()
+                .zip(&other.genes)
+                .all(|(a, b)| a.to_bits() == b.to_bits())
+    }
+}
+
+#[cfg(feature = "global_cache")]
+impl Eq for MyIndividual {}
+
 impl Individual for MyIndividual {
     fn fitness(&self) -> f64 {
         self.genes.iter().sum()
@@ -178,13 +807,16 @@ impl<I: Individual> CrossoverOperator<I> for SinglePointCrossover {
 }
 
 struct GaussianMutation {
-    mutation_rate: f64,
-    mutation_strength: f64,
+    mutation_rate: Box<dyn Rate>,
 }
 
 impl<I: Individual> MutationOperator<I> for GaussianMutation {
-    fn mutate<R: Rng>(&self, individual: &mut I, _rng: &mut R) {
-        if thread_rng().gen_bool(self.mutation_rate) {
+    fn mutate<R: Rng>(&self, individual: &mut I, ctx: &EvolveContext, rng: &mut R) {
+        let rate = self
+            .mutation_rate
+            .get(ctx.generation, ctx.progress, ctx.n_solutions)
+            .clamp(0.0, 1.0);
+        if rng.gen_bool(rate) {
             individual.mutate();
         }
     }
@@ -198,8 +830,7 @@ fn main() {
     let selection_strategy = TournamentSelection { tournament_size: 3 };
     let crossover_operator = SinglePointCrossover;
     let mutation_operator = GaussianMutation {
-        mutation_rate: 0.1,
-        mutation_strength: 0.1,
+        mutation_rate: Box::new(ConstantRate(0.1)),
     };
 
     let ga = GeneticAlgorithm::new(
@@ -207,7 +838,8 @@ fn main() {
         selection_strategy,
         crossover_operator,
         mutation_operator,
-    );
+    )
+    .with_elitism(2);
 
     let initial_population: Vec<MyIndividual> = (0..population_size)
         .map(|_| MyIndividual {
@@ -216,7 +848,23 @@ fn main() {
         .collect();
 
     let mut rng = thread_rng();
-    let final_population = ga.evolve(generations, &mut rng, Some(initial_population));
+    let stop_criterion = Or(MaxGenerations(generations), FitnessThreshold(gene_length as f64));
+    let mut log_progress = |stats: &GenerationStats<MyIndividual>| {
+        println!(
+            "gen {}: best={:.4} mean={:.4} std={:.4} distinct={}",
+            stats.generation,
+            stats.best_fitness,
+            stats.mean_fitness,
+            stats.std_fitness,
+            stats.num_distinct,
+        );
+    };
+    let final_population = ga.evolve(
+        &stop_criterion,
+        &mut rng,
+        Some(initial_population),
+        Some(&mut log_progress as &mut dyn GenerationObserver<MyIndividual>),
+    );
 
     let best_individual = final_population
         .into_iter()
@@ -226,3 +874,182 @@ fn main() {
     println!("Best individual: {:?}", best_individual);
     println!("Fitness: {}", best_individual.fitness());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn individual(fitness: f64) -> MyIndividual {
+        MyIndividual {
+            genes: vec![fitness],
+        }
+    }
+
+    fn ctx(objective: Objective) -> EvolveContext {
+        EvolveContext {
+            generation: 0,
+            progress: 0.0,
+            n_solutions: 0,
+            objective,
+        }
+    }
+
+    #[test]
+    fn cumulative_probabilities_normalizes() {
+        let cumulative = cumulative_probabilities(&[1.0, 1.0, 1.0, 1.0], 4.0);
+        assert!(approx(cumulative[0], 0.25));
+        assert!(approx(cumulative[1], 0.5));
+        assert!(approx(cumulative[2], 0.75));
+        assert!(approx(cumulative[3], 1.0));
+    }
+
+    #[test]
+    fn cumulative_probabilities_falls_back_to_uniform() {
+        let cumulative = cumulative_probabilities(&[0.0, 0.0], 0.0);
+        assert!(approx(cumulative[0], 0.5));
+        assert!(approx(cumulative[1], 1.0));
+    }
+
+    #[test]
+    fn least_squares_slope_of_a_line() {
+        assert!(approx(least_squares_slope(&[0.0, 1.0, 2.0, 3.0]), 1.0));
+        assert!(approx(least_squares_slope(&[5.0]), 0.0));
+    }
+
+    #[test]
+    fn roulette_wheel_favours_fitter_individuals() {
+        let population: Vec<MyIndividual> = [1.0, 2.0, 3.0, 4.0].iter().map(|&f| individual(f)).collect();
+        let fitness = vec![1.0, 2.0, 3.0, 4.0];
+        let mut rng = StdRng::seed_from_u64(42);
+        let picks = RouletteWheelSelection.select(&population, &fitness, &ctx(Objective::Maximize), &mut rng);
+        let best = picks.iter().filter(|i| approx(i.fitness(), 4.0)).count();
+        let worst = picks.iter().filter(|i| approx(i.fitness(), 1.0)).count();
+        assert!(best > worst);
+    }
+
+    #[test]
+    fn rank_selection_never_picks_the_worst_at_max_pressure() {
+        let population: Vec<MyIndividual> = [1.0, 2.0, 3.0, 4.0].iter().map(|&f| individual(f)).collect();
+        let fitness = vec![1.0, 2.0, 3.0, 4.0];
+        let mut rng = StdRng::seed_from_u64(7);
+        let strategy = RankSelection {
+            selective_pressure: 2.0,
+        };
+        let picks = strategy.select(&population, &fitness, &ctx(Objective::Maximize), &mut rng);
+        // At s = 2 the lowest rank is assigned probability 0.
+        assert!(picks.iter().all(|i| !approx(i.fitness(), 1.0)));
+    }
+
+    #[test]
+    fn rank_selection_respects_minimize() {
+        let population: Vec<MyIndividual> = [1.0, 2.0, 3.0, 4.0].iter().map(|&f| individual(f)).collect();
+        let fitness = vec![1.0, 2.0, 3.0, 4.0];
+        let mut rng = StdRng::seed_from_u64(7);
+        let strategy = RankSelection {
+            selective_pressure: 2.0,
+        };
+        let picks = strategy.select(&population, &fitness, &ctx(Objective::Minimize), &mut rng);
+        // Under minimize the worst (and never-selected) individual is the one
+        // with the highest fitness.
+        assert!(picks.iter().all(|i| !approx(i.fitness(), 4.0)));
+    }
+
+    #[test]
+    fn fitness_threshold_honours_objective() {
+        let max = FitnessThreshold(0.9);
+        assert!(StopCriterion::<MyIndividual>::should_stop(&max, 0, 0.95, &[], Objective::Maximize));
+        assert!(!StopCriterion::<MyIndividual>::should_stop(&max, 0, 0.8, &[], Objective::Maximize));
+        let min = FitnessThreshold(0.2);
+        assert!(StopCriterion::<MyIndividual>::should_stop(&min, 0, 0.1, &[], Objective::Minimize));
+        assert!(!StopCriterion::<MyIndividual>::should_stop(&min, 0, 0.3, &[], Objective::Minimize));
+    }
+
+    #[test]
+    fn max_generations_stops_at_limit() {
+        let c = MaxGenerations(5);
+        assert!(!StopCriterion::<MyIndividual>::should_stop(&c, 4, 0.0, &[], Objective::Maximize));
+        assert!(StopCriterion::<MyIndividual>::should_stop(&c, 5, 0.0, &[], Objective::Maximize));
+    }
+
+    #[test]
+    fn slope_stagnation_handles_both_directions() {
+        let c = SlopeStagnation {
+            window: 3,
+            epsilon: 0.5,
+        };
+        // Still improving: should not stop in either direction.
+        assert!(!StopCriterion::<MyIndividual>::should_stop(
+            &c,
+            0,
+            0.0,
+            &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+            Objective::Maximize,
+        ));
+        assert!(!StopCriterion::<MyIndividual>::should_stop(
+            &c,
+            0,
+            0.0,
+            &[5.0, 4.0, 3.0, 2.0, 1.0, 0.0],
+            Objective::Minimize,
+        ));
+        // Stalled: should stop.
+        assert!(StopCriterion::<MyIndividual>::should_stop(
+            &c,
+            0,
+            0.0,
+            &[0.0, 1.0, 2.0, 2.0, 2.0, 2.0],
+            Objective::Maximize,
+        ));
+    }
+
+    #[test]
+    fn and_or_combine_criteria() {
+        let or = Or(MaxGenerations(10), FitnessThreshold(0.99));
+        assert!(StopCriterion::<MyIndividual>::should_stop(&or, 0, 1.0, &[], Objective::Maximize));
+        assert!(StopCriterion::<MyIndividual>::should_stop(&or, 10, 0.0, &[], Objective::Maximize));
+        assert!(!StopCriterion::<MyIndividual>::should_stop(&or, 0, 0.0, &[], Objective::Maximize));
+
+        let and = And(MaxGenerations(10), FitnessThreshold(0.99));
+        assert!(!StopCriterion::<MyIndividual>::should_stop(&and, 10, 0.0, &[], Objective::Maximize));
+        assert!(StopCriterion::<MyIndividual>::should_stop(&and, 10, 1.0, &[], Objective::Maximize));
+    }
+
+    #[test]
+    fn constant_and_linear_rates() {
+        assert!(approx(ConstantRate(0.3).get(0, 0.0, 0), 0.3));
+        let linear = LinearRate {
+            start: 0.1,
+            end: 0.5,
+            over_generations: 10,
+        };
+        assert!(approx(linear.get(0, 0.0, 0), 0.1));
+        assert!(approx(linear.get(5, 0.0, 0), 0.3));
+        assert!(approx(linear.get(10, 0.0, 0), 0.5));
+        assert!(approx(linear.get(20, 0.0, 0), 0.5));
+    }
+
+    #[test]
+    fn slope_rate_reacts_to_progress_and_clamps() {
+        let rate = SlopeRate {
+            a: 0.4,
+            b: 0.1,
+            c: 1.0,
+        };
+        // Stalled (slope 0) sits at a/2 + b; accelerating progress lowers it.
+        assert!(approx(rate.get(0, 0.0, 0), 0.3));
+        assert!(rate.get(0, 10.0, 0) < rate.get(0, 0.0, 0));
+        assert!(rate.get(0, -10.0, 0) > rate.get(0, 0.0, 0));
+        // Output is clamped to [0, 1].
+        let steep = SlopeRate {
+            a: 5.0,
+            b: 0.0,
+            c: 1.0,
+        };
+        let value = steep.get(0, -10.0, 0);
+        assert!((0.0..=1.0).contains(&value));
+    }
+}